@@ -58,6 +58,20 @@ impl Interceptor for BearerTokenInterceptor {
     }
 }
 
+#[async_trait::async_trait]
+impl super::CredentialProvider for BearerTokenInterceptor {
+    async fn auth_header(
+        &self,
+    ) -> Result<tonic::metadata::MetadataValue<tonic::metadata::Ascii>, super::CredentialRefreshError>
+    {
+        Ok(self.token.clone())
+    }
+
+    fn method_name(&self) -> &'static str {
+        "bearer_token"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,227 @@
+//! Extension point for pluggable authentication schemes.
+use http::header::AUTHORIZATION;
+use std::{
+    sync::{atomic::AtomicBool, Arc, RwLock},
+    time::{Duration, Instant},
+};
+use tonic::{
+    metadata::{Ascii, MetadataValue},
+    service::interceptor::Interceptor,
+};
+
+use super::CredentialRefreshError;
+
+/// A pluggable source of `Authorization` header values.
+///
+/// Implement this trait to authenticate with `OpenFGA` using a scheme this
+/// crate does not ship out of the box, e.g. AWS `SigV4`-signed headers, Azure
+/// managed identity, or a custom internal STS. Wrap the provider in a
+/// [`CredentialInterceptor`] to turn it into a gRPC `Interceptor`.
+///
+/// Implementations are responsible for their own caching/refresh semantics;
+/// `auth_header` may be called once per request whose cache in
+/// [`CredentialInterceptor`] has expired, so it should be cheap when the
+/// underlying credential is still valid.
+#[async_trait::async_trait]
+pub trait CredentialProvider: Send + Sync + std::fmt::Debug {
+    /// Produce a fresh `Authorization` header value.
+    ///
+    /// # Errors
+    /// Returns an error if the header cannot be produced, e.g. because a
+    /// token endpoint could not be reached.
+    async fn auth_header(&self) -> Result<MetadataValue<Ascii>, CredentialRefreshError>;
+
+    /// A short name identifying the authentication method, used for diagnostics.
+    fn method_name(&self) -> &'static str;
+}
+
+struct CredentialInterceptorInner<P> {
+    provider: P,
+    cached: RwLock<Option<(MetadataValue<Ascii>, Instant)>>,
+    /// Guards against multiple concurrent refreshes: the caller that wins the
+    /// compare-exchange performs the fetch, everyone else waits for it to
+    /// finish and reuses its result instead of issuing a second request.
+    refresh_active: AtomicBool,
+    /// Dedicated runtime used to drive `provider.auth_header()` from this
+    /// interceptor's synchronous `Interceptor::call`. Built once and reused,
+    /// rather than spinning up a fresh runtime (and OS thread) per call.
+    runtime: tokio::runtime::Runtime,
+}
+
+/// gRPC `Interceptor` that authenticates requests using a [`CredentialProvider`].
+///
+/// The interceptor caches the header returned by the provider for a short
+/// time and only calls back into the provider once that cache is empty or
+/// has expired. It does not insert the access token if the intercepted call
+/// already has an `Authorization` header, matching the behavior of
+/// [`BearerTokenInterceptor`](super::BearerTokenInterceptor) and
+/// [`ClientCredentialInterceptor`](super::ClientCredentialInterceptor).
+///
+/// # Examples
+/// ```no_run
+/// use openfga_rs::open_fga_service_client::OpenFgaServiceClient;
+/// use openfga_rs::authentication::{CredentialInterceptor, BearerTokenInterceptor};
+/// use tonic::transport::Endpoint;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let provider = BearerTokenInterceptor::new("my-token").unwrap();
+///     let interceptor = CredentialInterceptor::new(provider);
+///     let channel = Endpoint::from_static("http://[::1]:50051")
+///         .connect()
+///         .await
+///         .unwrap();
+///     let _client = OpenFgaServiceClient::with_interceptor(channel, interceptor);
+///
+///     println!("Connected to OpenFGA service");
+/// }
+/// ```
+pub struct CredentialInterceptor<P> {
+    inner: Arc<CredentialInterceptorInner<P>>,
+}
+
+impl<P> Clone for CredentialInterceptor<P> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<P: CredentialProvider> CredentialInterceptor<P> {
+    /// Cached headers are re-validated with the provider after this long.
+    const CACHE_TTL: Duration = Duration::from_mins(1);
+
+    /// Create a new [`CredentialInterceptor`] wrapping the given provider.
+    ///
+    /// # Panics
+    /// Panics if the dedicated runtime used to drive the provider cannot be started.
+    #[must_use]
+    pub fn new(provider: P) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start credential-refresh runtime");
+
+        Self {
+            inner: Arc::new(CredentialInterceptorInner {
+                provider,
+                cached: RwLock::new(None),
+                refresh_active: AtomicBool::new(false),
+                runtime,
+            }),
+        }
+    }
+
+    fn cached_header(&self) -> Option<MetadataValue<Ascii>> {
+        let guard = self.inner.cached.read().expect("poisoned lock");
+        guard
+            .as_ref()
+            .filter(|(_, fetched_at)| fetched_at.elapsed() < Self::CACHE_TTL)
+            .map(|(value, _)| value.clone())
+    }
+
+    fn store_header(&self, value: MetadataValue<Ascii>) {
+        let mut guard = self.inner.cached.write().expect("poisoned lock");
+        *guard = Some((value, Instant::now()));
+    }
+
+    /// Fetch a fresh header from the provider, deduplicating concurrent
+    /// refreshes via `refresh_active` so only one call into the provider is
+    /// ever in flight; other callers wait for it to finish and reuse its
+    /// result instead of calling the provider again.
+    fn refresh_header(&self) -> Result<MetadataValue<Ascii>, CredentialRefreshError> {
+        self.inner.runtime.block_on(super::refresh::single_flight(
+            &self.inner.refresh_active,
+            || self.cached_header(),
+            async {
+                let header = self.inner.provider.auth_header().await?;
+                self.store_header(header.clone());
+                Ok(header)
+            },
+        ))
+    }
+}
+
+impl<P: std::fmt::Debug> std::fmt::Debug for CredentialInterceptor<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CredentialInterceptor")
+            .field("provider", &self.inner.provider)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P: CredentialProvider + 'static> Interceptor for CredentialInterceptor<P> {
+    fn call(&mut self, mut request: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
+        let metadata = request.metadata_mut();
+        if metadata.contains_key(AUTHORIZATION.as_str()) {
+            return Ok(request);
+        }
+
+        let header = match self.cached_header() {
+            Some(header) => header,
+            None => self.refresh_header()?,
+        };
+
+        metadata.insert(AUTHORIZATION.as_str(), header);
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct StaticProvider(&'static str);
+
+    #[async_trait::async_trait]
+    impl CredentialProvider for StaticProvider {
+        async fn auth_header(&self) -> Result<MetadataValue<Ascii>, CredentialRefreshError> {
+            Ok(format!("Bearer {}", self.0)
+                .parse()
+                .expect("valid header value"))
+        }
+
+        fn method_name(&self) -> &'static str {
+            "static"
+        }
+    }
+
+    #[test]
+    fn test_header_added_for_custom_provider() {
+        let mut interceptor = CredentialInterceptor::new(StaticProvider("my-token"));
+
+        let request = tonic::Request::new(());
+        assert!(request.metadata().is_empty());
+        let modified_request = interceptor.call(request).unwrap();
+
+        let metadata = modified_request.metadata();
+        assert!(metadata.contains_key("authorization"));
+        assert_eq!(
+            metadata.get("authorization").unwrap().to_str().unwrap(),
+            "Bearer my-token"
+        );
+    }
+
+    #[test]
+    fn test_header_not_added_if_authorization_present() {
+        let mut interceptor = CredentialInterceptor::new(StaticProvider("my-token"));
+
+        let mut request = tonic::Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer existing-token".parse().unwrap());
+
+        let modified_request = interceptor.call(request).unwrap();
+        assert_eq!(
+            modified_request
+                .metadata()
+                .get("authorization")
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            "Bearer existing-token"
+        );
+    }
+}
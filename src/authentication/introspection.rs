@@ -0,0 +1,231 @@
+//! `OAuth2` token introspection [RFC 7662].
+use std::collections::HashMap;
+
+use http::header::ACCEPT;
+
+use super::client_credentials::client_auth_params;
+use super::{ClientAuth, CredentialRefreshError};
+
+/// A token is treated as already expired once it has less than this many
+/// seconds of life left, even if the `IdP` still reports it as active.
+const MIN_TIME_LEFT_SECONDS: i64 = 60;
+
+/// Client for validating bearer tokens against an `IdP`'s `OAuth2` token
+/// introspection endpoint [RFC 7662].
+///
+/// `openfga-rs` only attaches tokens to outgoing requests; services built on
+/// top of it that themselves *receive* bearer tokens can use this to confirm
+/// a token is live and carries the expected scope before, e.g., forwarding an
+/// FGA `Check` on the caller's behalf. Authenticates against the
+/// introspection endpoint using the same [`ClientAuth`] mechanisms as
+/// [`ClientCredentialInterceptor`](super::ClientCredentialInterceptor).
+///
+/// # Examples
+/// ```no_run
+/// use openfga_rs::authentication::{ClientAuth, TokenIntrospector};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let introspector = TokenIntrospector::new(
+///         "my-client",
+///         ClientAuth::Secret("my-secret".to_string()),
+///         "http://my.idp.example.com/my-tenant/oauth2/introspect",
+///     );
+///
+///     let info = introspector.introspect("some-bearer-token").await.unwrap();
+///     println!("token active: {}", info.is_active());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenIntrospector {
+    client_id: String,
+    client_auth: ClientAuth,
+    introspection_endpoint: String,
+    client: reqwest::Client,
+}
+
+impl TokenIntrospector {
+    /// Create a new [`TokenIntrospector`].
+    #[must_use]
+    pub fn new(
+        client_id: impl Into<String>,
+        client_auth: ClientAuth,
+        introspection_endpoint: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_auth,
+            introspection_endpoint: introspection_endpoint.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Introspect `token` against the configured endpoint.
+    ///
+    /// # Errors
+    /// Returns an error if the request could not be built or sent, or if the
+    /// endpoint's response could not be parsed.
+    pub async fn introspect(&self, token: &str) -> Result<IntrospectInfo, CredentialRefreshError> {
+        let mut params: HashMap<&str, String> = HashMap::with_capacity(4);
+        params.insert("token", token.to_string());
+        params.insert("client_id", self.client_id.clone());
+        params.extend(client_auth_params(
+            &self.client_id,
+            &self.introspection_endpoint,
+            &self.client_auth,
+        )?);
+
+        let request = self
+            .client
+            .request(http::Method::POST, &self.introspection_endpoint)
+            .header(ACCEPT, "application/json")
+            .form(&params)
+            .build()
+            .map_err(CredentialRefreshError::InvalidConfiguration)?;
+
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(CredentialRefreshError::InvalidRequest)?;
+
+        match response.status().as_u16() {
+            200..=299 => response
+                .json()
+                .await
+                .map_err(CredentialRefreshError::ParseError),
+            code => Err(CredentialRefreshError::NonRetryableError {
+                code,
+                body: response.text().await.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+/// Response to an `OAuth2` token introspection request [RFC 7662].
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct IntrospectInfo {
+    /// Whether the token is currently active, as reported by the `IdP`.
+    pub active: bool,
+    /// Space-separated list of scopes granted to the token, if returned.
+    pub scope: Option<String>,
+    /// Unix timestamp the token expires at, if returned.
+    pub exp: Option<i64>,
+    /// Subject the token was issued for, if returned.
+    pub sub: Option<String>,
+    /// Client the token was issued to, if returned.
+    pub client_id: Option<String>,
+    /// Intended audience of the token, if returned.
+    pub aud: Option<String>,
+}
+
+impl IntrospectInfo {
+    /// Whether the token should be treated as active.
+    ///
+    /// In addition to the `IdP`'s own `active` flag, a token with less than
+    /// `60` seconds of life left is treated as already expired, to avoid
+    /// racing the `IdP`'s own expiry.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        if !self.active {
+            return false;
+        }
+
+        match self.exp {
+            Some(exp) => exp > chrono::Utc::now().timestamp() + MIN_TIME_LEFT_SECONDS,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_introspect_active_token() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", mockito::Matcher::Any)
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("token=my-token".to_string()),
+                mockito::Matcher::Regex("client_id=my-client".to_string()),
+                mockito::Matcher::Regex("client_secret=my-secret".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "active": true,
+                    "scope": "read write",
+                    "exp": chrono::Utc::now().timestamp() + 3600,
+                    "sub": "some-subject",
+                    "client_id": "my-client",
+                    "aud": "my-audience"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let introspector = TokenIntrospector::new(
+            "my-client",
+            ClientAuth::Secret("my-secret".to_string()),
+            server.url(),
+        );
+
+        let info = introspector.introspect("my-token").await.unwrap();
+        assert!(info.is_active());
+        assert_eq!(info.scope.as_deref(), Some("read write"));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_introspect_inactive_token() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({ "active": false }).to_string())
+            .create_async()
+            .await;
+
+        let introspector = TokenIntrospector::new(
+            "my-client",
+            ClientAuth::Secret("my-secret".to_string()),
+            server.url(),
+        );
+
+        let info = introspector.introspect("my-token").await.unwrap();
+        assert!(!info.is_active());
+    }
+
+    #[tokio::test]
+    async fn test_introspect_token_expiring_soon_is_not_active() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "active": true,
+                    "exp": chrono::Utc::now().timestamp() + 10
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let introspector = TokenIntrospector::new(
+            "my-client",
+            ClientAuth::Secret("my-secret".to_string()),
+            server.url(),
+        );
+
+        let info = introspector.introspect("my-token").await.unwrap();
+        assert!(!info.is_active());
+    }
+}
@@ -3,8 +3,15 @@
 
 mod bearer_token;
 mod client_credentials;
+mod credential;
+mod introspection;
+mod refresh;
 
 pub use bearer_token::BearerTokenInterceptor;
 pub use client_credentials::{
-    ClientCredentialInterceptor, ClientCredentials, RefreshConfiguration,
+    CachedToken, ClientAuth, ClientCredentialInterceptor, ClientCredentials,
+    CredentialRefreshError, FileTokenStore, InMemoryTokenStore, PrivateKeyJwtSigningKey,
+    RefreshConfiguration, TokenStore,
 };
+pub use credential::{CredentialInterceptor, CredentialProvider};
+pub use introspection::{IntrospectInfo, TokenIntrospector};
@@ -0,0 +1,53 @@
+//! Single-flight refresh primitive shared by [`super::CredentialInterceptor`]
+//! and [`super::ClientCredentialInterceptor`].
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use super::CredentialRefreshError;
+
+/// How often a caller waiting on an in-flight refresh re-checks whether it has finished.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Drive a single-flight, deduplicated refresh guarded by `refresh_active`:
+/// the caller that wins the compare-exchange awaits `fetch` and returns its
+/// result; every other concurrent caller waits for it to finish and reuses
+/// whatever `cached` now returns instead of calling `fetch` itself.
+pub(super) async fn single_flight<T>(
+    refresh_active: &AtomicBool,
+    mut cached: impl FnMut() -> Option<T>,
+    fetch: impl std::future::Future<Output = Result<T, CredentialRefreshError>>,
+) -> Result<T, CredentialRefreshError> {
+    loop {
+        if refresh_active
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            break;
+        }
+
+        // Another caller is already refreshing; wait for it to finish and reuse its result.
+        while refresh_active.load(Ordering::Acquire) {
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        if let Some(value) = cached() {
+            return Ok(value);
+        }
+        // The in-flight refresh left no valid value; retry ourselves.
+    }
+
+    let _clear_on_drop = ClearOnDrop(refresh_active);
+    fetch.await
+}
+
+/// Guard that resets `refresh_active` to `false` on drop, including on early
+/// return or panic, so a failed refresh doesn't permanently wedge out future
+/// callers.
+struct ClearOnDrop<'a>(&'a AtomicBool);
+
+impl Drop for ClearOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}